@@ -1,169 +1,620 @@
-use std::fmt::Debug;
-
-use proc_macro2::{Ident, TokenStream};
-use quote::{quote, ToTokens};
-use syn::{Arm, Attribute, Data, DeriveInput};
-
-const HANDLER_ATTR_NAME: &str = "handler";
-const ARG_ATTR_NAME: &str = "execute_with";
-
-#[derive(Debug)]
-enum Opts<'a> {
-    None,
-    RefParam(&'a str),
-    RefMutParam(&'a str),
-}
-
-#[derive(Debug)]
-struct IncrementalId {
-    from: i32,
-}
-
-fn base_derive_macro(input: DeriveInput, opts: Opts) -> TokenStream {
-    let enum_ident = input.ident;
-
-    let variants = match input.data {
-        Data::Enum(e) => e.variants,
-        _ => panic!("Only `enum` types are supported"),
-    };
-
-    let arms = variants
-        .into_iter()
-        .map(|variant| {
-            let handler_attrs: Vec<Attribute> = variant
-                .attrs
-                .into_iter()
-                .filter(|attr| matches!(attr.path.get_ident(), Some(ident) if ident == HANDLER_ATTR_NAME))
-                .collect();
-
-            if handler_attrs.len() != 1 {
-                panic!(
-                    "Variant `{}` is missing attribute #[handler(your_handler_function)]",
-                    variant.ident
-                );
-            }
-
-            let handler_attr = &handler_attrs[0];
-            let handler_token = handler_attr.tokens.to_string();
-            let handler_name = match handler_token[1..handler_token.len() - 1].to_string() {
-                s if s.contains("::") => s,
-                u => format!("{}::{}", enum_ident.to_string(), u),
-            };
-
-            let field_placeholders: Vec<String> = variant
-                .fields
-                .into_iter()
-                .enumerate()
-                .map(|(index, field)| match field.ident {
-                    Some(ident) => ident.to_string(),
-                    None => format!("__{}", index + 1),
-                })
-                .collect();
-
-            // TODO(bengreenier): This could be cleaned up now that deeper inspection of ident is no longer needed
-            let handler_pipeline_arg = match &opts {
-                Opts::None => "".to_string(),
-                Opts::RefParam(ident) => ident.to_string(),
-                Opts::RefMutParam(ident) => ident.to_string(),
-            };
-
-            let arm_text = match field_placeholders.len() {
-                0 => format!(
-                    "{}::{} => {}({})",
-                    enum_ident, variant.ident, handler_name, handler_pipeline_arg
-                ),
-                _ => {
-                    let pl = field_placeholders.join(",");
-                    let mut pl_with_arg = field_placeholders;
-                    pl_with_arg.extend_from_slice(&[handler_pipeline_arg]);
-
-                    format!(
-                        "{}::{}({}) => {}({})",
-                        enum_ident,
-                        variant.ident,
-                        pl,
-                        handler_name,
-                        pl_with_arg.join(",")
-                    )
-                }
-            };
-
-            syn::parse_str::<Arm>(&arm_text).expect("Failed to generate a variant arm")
-        })
-        .collect::<Vec<Arm>>();
-
-    quote! {
-        match self {
-            #(#arms),*
-        }
-    }
-}
-
-fn parse_argtype(attrs: &[Attribute], ident: &Ident) -> Ident {
-    let arg_type_attrs: Vec<&Attribute> = attrs
-        .iter()
-        .filter(|attr| matches!(attr.path.get_ident(), Some(ident) if ident == ARG_ATTR_NAME))
-        .collect();
-
-    if arg_type_attrs.len() != 1 {
-        panic!(
-            "Enum `{}` is missing attribute #[argtype(your_arg_type)]",
-            ident
-        );
-    }
-
-    let arg_type_attr = &arg_type_attrs[0];
-    let arg_type_token = arg_type_attr.tokens.to_string();
-    let arg_type_name = arg_type_token[1..arg_type_token.len() - 1].to_string();
-
-    syn::parse_str::<Ident>(&arg_type_name)
-        .unwrap_or_else(|_| panic!("Failed to parse argtype attribute on Enum `{}`", ident))
-}
-
-pub fn execute_derive_macro(input: DeriveInput) -> TokenStream {
-    let enum_ident = input.ident.clone();
-    let matcher = base_derive_macro(input, Opts::None);
-
-    quote! {
-        #[automatically_derived]
-        impl Execute for #enum_ident {
-            fn execute(self) {
-                #matcher
-            }
-        }
-    }
-}
-
-pub fn execute_with_derive_macro(input: DeriveInput) -> TokenStream {
-    let enum_ident = input.ident.clone();
-    let arg_type = parse_argtype(&input.attrs, &input.ident);
-    let matcher = base_derive_macro(input, Opts::RefParam("args"));
-
-    let arg_type_ts = arg_type.into_token_stream();
-
-    quote! {
-        #[automatically_derived]
-        impl ExecuteWith<#arg_type_ts> for #enum_ident {
-            fn execute_with(self, args: &#arg_type_ts) {
-                #matcher
-            }
-        }
-    }
-}
-
-pub fn execute_with_mut_derive_macro(input: DeriveInput) -> TokenStream {
-    let enum_ident = input.ident.clone();
-    let arg_type = parse_argtype(&input.attrs, &input.ident);
-    let matcher = base_derive_macro(input, Opts::RefMutParam("args"));
-
-    let arg_type_ts = arg_type.into_token_stream();
-
-    quote! {
-        #[automatically_derived]
-        impl ExecuteWithMut<#arg_type_ts> for #enum_ident {
-            fn execute_with_mut(self, args: &mut #arg_type_ts) {
-                #matcher
-            }
-        }
-    }
-}
+use std::collections::HashSet;
+use std::fmt::Debug;
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{quote, ToTokens};
+use syn::parse::{Parse, ParseStream};
+use syn::{Arm, Attribute, Data, DeriveInput, Expr, Token};
+
+const HANDLER_ATTR_NAME: &str = "handler";
+const ARG_ATTR_NAME: &str = "execute_with";
+const DELEGATE_ATTR_NAME: &str = "delegate";
+
+#[derive(Debug)]
+enum Opts<'a> {
+    None,
+    RefParam(&'a str),
+    RefMutParam(&'a str),
+}
+
+#[derive(Debug)]
+struct IncrementalId {
+    from: i32,
+}
+
+/// The parsed contents of a `#[handler(...)]` attribute: a handler path,
+/// optionally followed by a comma-separated list of call arguments, e.g.
+/// the `3, __1` in `#[handler(handle_run, 3, __1)]`.
+///
+/// These arguments are *not* matched against the handler's real parameter
+/// names — the macro only ever sees a path to the function, never its
+/// signature, so there's nothing to match them against. When given, they're
+/// emitted to the call positionally, in the order they're written, in place
+/// of the default "fields then context" argument list. That's enough to let
+/// a handler skip fields, reorder them, or take a constant the variant
+/// doesn't carry — but it's on the caller to get the order right; the macro
+/// has no way to check it.
+struct HandlerSpec {
+    path: syn::Path,
+    args: Vec<Expr>,
+}
+
+impl Parse for HandlerSpec {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let path: syn::Path = input.parse()?;
+        let mut args = Vec::new();
+
+        while input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+
+            // allow a trailing comma after the last argument
+            if input.is_empty() {
+                break;
+            }
+
+            args.push(input.parse()?);
+        }
+
+        Ok(HandlerSpec { path, args })
+    }
+}
+
+fn base_derive_macro(input: DeriveInput, opts: Opts, try_mode: bool) -> TokenStream {
+    let enum_ident = input.ident;
+
+    let variants = match input.data {
+        Data::Enum(e) => e.variants,
+        _ => panic!("Only `enum` types are supported"),
+    };
+
+    let arms = variants
+        .into_iter()
+        .map(|variant| {
+            let variant_ident = variant.ident;
+
+            let is_delegate = variant
+                .attrs
+                .iter()
+                .any(|attr| matches!(attr.path.get_ident(), Some(ident) if ident == DELEGATE_ATTR_NAME));
+
+            if is_delegate {
+                let field_count = variant.fields.len();
+                if field_count != 1 {
+                    panic!(
+                        "#[delegate] is only supported on single-field variants, found {} fields on variant `{}`",
+                        field_count, variant_ident
+                    );
+                }
+
+                let delegate_ident = Ident::new("__delegate", Span::call_site());
+
+                let pattern = match &variant.fields {
+                    syn::Fields::Named(fields) => {
+                        let field_ident = &fields.named.first().unwrap().ident;
+                        quote! { #enum_ident::#variant_ident { #field_ident: #delegate_ident } }
+                    }
+                    syn::Fields::Unnamed(_) => {
+                        quote! { #enum_ident::#variant_ident(#delegate_ident) }
+                    }
+                    syn::Fields::Unit => unreachable!("field_count == 1 excludes unit variants"),
+                };
+
+                let call = match (&opts, try_mode) {
+                    (Opts::None, false) => quote! { #delegate_ident.execute() },
+                    (Opts::None, true) => quote! { #delegate_ident.try_execute()? },
+                    (Opts::RefParam(ident), false) => {
+                        let ctx_ident = Ident::new(ident, Span::call_site());
+                        quote! { #delegate_ident.execute_with(#ctx_ident) }
+                    }
+                    (Opts::RefParam(ident), true) => {
+                        let ctx_ident = Ident::new(ident, Span::call_site());
+                        quote! { #delegate_ident.try_execute_with(#ctx_ident)? }
+                    }
+                    (Opts::RefMutParam(ident), false) => {
+                        let ctx_ident = Ident::new(ident, Span::call_site());
+                        quote! { #delegate_ident.execute_with_mut(#ctx_ident) }
+                    }
+                    (Opts::RefMutParam(ident), true) => {
+                        let ctx_ident = Ident::new(ident, Span::call_site());
+                        quote! { #delegate_ident.try_execute_with_mut(#ctx_ident)? }
+                    }
+                };
+
+                return syn::parse2::<Arm>(quote! { #pattern => #call })
+                    .expect("Failed to generate a variant arm");
+            }
+
+            let handler_attrs: Vec<Attribute> = variant
+                .attrs
+                .into_iter()
+                .filter(|attr| matches!(attr.path.get_ident(), Some(ident) if ident == HANDLER_ATTR_NAME))
+                .collect();
+
+            if handler_attrs.len() != 1 {
+                panic!(
+                    "Variant `{}` is missing attribute #[handler(your_handler_function)]",
+                    variant_ident
+                );
+            }
+
+            let handler_attr = &handler_attrs[0];
+            let handler_spec = handler_attr.parse_args::<HandlerSpec>().unwrap_or_else(|e| {
+                panic!(
+                    "Failed to parse #[handler(...)] on variant `{}`: {}",
+                    variant_ident, e
+                )
+            });
+
+            // a bare single-ident handler path is resolved on the enum itself,
+            // anything else (already-qualified paths) is used as-is
+            let handler_path = &handler_spec.path;
+            let handler_name = if handler_path.get_ident().is_some() {
+                quote! { #enum_ident::#handler_path }
+            } else {
+                quote! { #handler_path }
+            };
+
+            let field_placeholders: Vec<String> = variant
+                .fields
+                .into_iter()
+                .enumerate()
+                .map(|(index, field)| match field.ident {
+                    Some(ident) => ident.to_string(),
+                    None => format!("__{}", index + 1),
+                })
+                .collect();
+
+            let placeholder_idents: Vec<Ident> = field_placeholders
+                .iter()
+                .map(|p| Ident::new(p, Span::call_site()))
+                .collect();
+
+            let pattern = if placeholder_idents.is_empty() {
+                quote! { #enum_ident::#variant_ident }
+            } else {
+                quote! { #enum_ident::#variant_ident(#(#placeholder_idents),*) }
+            };
+
+            // TODO(bengreenier): This could be cleaned up now that deeper inspection of ident is no longer needed
+            let handler_pipeline_arg = match &opts {
+                Opts::None => None,
+                Opts::RefParam(ident) => Some(*ident),
+                Opts::RefMutParam(ident) => Some(*ident),
+            };
+
+            let call = if handler_spec.args.is_empty() {
+                // positional (default) behavior: fields in declaration order,
+                // followed by the context argument (if any)
+                let mut call_args: Vec<TokenStream> = placeholder_idents
+                    .iter()
+                    .map(|i| i.to_token_stream())
+                    .collect();
+                if let Some(ctx) = handler_pipeline_arg {
+                    let ctx_ident = Ident::new(ctx, Span::call_site());
+                    call_args.push(ctx_ident.to_token_stream());
+                }
+
+                quote! { #handler_name(#(#call_args),*) }
+            } else {
+                // explicit argument list: replaces the default "fields then
+                // context" call entirely, emitted positionally in the order
+                // they're written (see `HandlerSpec`)
+                let valid_placeholders: HashSet<&str> = field_placeholders
+                    .iter()
+                    .map(|p| p.as_str())
+                    .chain(handler_pipeline_arg)
+                    .collect();
+
+                for arg in &handler_spec.args {
+                    if let Expr::Path(expr_path) = arg {
+                        if let Some(path_ident) = expr_path.path.get_ident() {
+                            let path_ident_s = path_ident.to_string();
+                            if !valid_placeholders.contains(path_ident_s.as_str()) {
+                                panic!(
+                                    "handler argument on variant `{}` references unknown placeholder `{}`",
+                                    variant_ident, path_ident_s
+                                );
+                            }
+                        }
+                    }
+                }
+
+                let call_args = &handler_spec.args;
+
+                quote! { #handler_name(#(#call_args),*) }
+            };
+
+            let call = if try_mode {
+                quote! { #call? }
+            } else {
+                call
+            };
+
+            syn::parse2::<Arm>(quote! { #pattern => #call }).expect("Failed to generate a variant arm")
+        })
+        .collect::<Vec<Arm>>();
+
+    let matcher = quote! {
+        match self {
+            #(#arms),*
+        }
+    };
+
+    if try_mode {
+        quote! {
+            #matcher
+
+            Ok(())
+        }
+    } else {
+        matcher
+    }
+}
+
+/// Converts a `PascalCase` variant identifier into its `snake_case` predicate
+/// name, e.g. `AddOne` -> `add_one`.
+fn to_snake_case(input: &str) -> String {
+    let mut out = String::with_capacity(input.len() + 4);
+
+    for (index, ch) in input.char_indices() {
+        if ch.is_uppercase() {
+            if index != 0 {
+                out.push('_');
+            }
+            out.extend(ch.to_lowercase());
+        } else {
+            out.push(ch);
+        }
+    }
+
+    out
+}
+
+/// Builds a wildcard pattern for `variant` regardless of its field shape, so
+/// callers can check "is this instance this variant" without caring what
+/// data it carries.
+fn variant_wildcard_pattern(enum_ident: &Ident, variant: &syn::Variant) -> TokenStream {
+    let variant_ident = &variant.ident;
+
+    match &variant.fields {
+        syn::Fields::Unit => quote! { #enum_ident::#variant_ident },
+        syn::Fields::Unnamed(_) => quote! { #enum_ident::#variant_ident(..) },
+        syn::Fields::Named(_) => quote! { #enum_ident::#variant_ident { .. } },
+    }
+}
+
+/// Generates an inherent `impl` block with `is_<variant>()` predicate
+/// methods and a `variant_name()` discriminant accessor for `enum_ident`,
+/// ported from `derive_more`'s `is_variant` idea.
+fn generate_variant_predicates(enum_ident: &Ident, data: &Data) -> TokenStream {
+    let variants = match data {
+        Data::Enum(e) => &e.variants,
+        _ => panic!("Only `enum` types are supported"),
+    };
+
+    let predicate_methods = variants.iter().map(|variant| {
+        let pattern = variant_wildcard_pattern(enum_ident, variant);
+        let predicate_name = Ident::new(
+            &format!("is_{}", to_snake_case(&variant.ident.to_string())),
+            Span::call_site(),
+        );
+        let doc = format!(
+            " Returns `true` if this instance is the `{}` variant.",
+            variant.ident
+        );
+
+        quote! {
+            #[doc = #doc]
+            pub fn #predicate_name(&self) -> bool {
+                matches!(self, #pattern)
+            }
+        }
+    });
+
+    let variant_name_arms = variants.iter().map(|variant| {
+        let pattern = variant_wildcard_pattern(enum_ident, variant);
+        let name = variant.ident.to_string();
+
+        quote! { #pattern => #name }
+    });
+
+    quote! {
+        #[automatically_derived]
+        impl #enum_ident {
+            #(#predicate_methods)*
+
+            /// Returns the name of the variant this instance currently is.
+            ///
+            /// This is a lightweight discriminant accessor, handy for
+            /// building custom predicates (e.g. with [`ExecuteFiltered`])
+            /// without needing a full `match`.
+            pub fn variant_name(&self) -> &'static str {
+                match self {
+                    #(#variant_name_arms),*
+                }
+            }
+        }
+    }
+}
+
+/// The derives that generate `is_<variant>()`/`variant_name()`. Deriving
+/// more than one of these on the same enum would otherwise emit the same
+/// inherent `impl` block twice (`E0592: duplicate definitions`), so only the
+/// first one named in the enum's `#[derive(...)]` list actually generates
+/// it; see [`is_designated_variants_source`].
+const VARIANT_PREDICATE_DERIVES: [&str; 3] = ["Execute", "ExecuteWith", "ExecuteWithMut"];
+
+/// Whether `own_name` is the first of [`VARIANT_PREDICATE_DERIVES`] listed
+/// in `attrs`' `#[derive(...)]`, i.e. whether this derive invocation is the
+/// one responsible for emitting the `is_<variant>()`/`variant_name()` impl
+/// block when more than one of them is derived on the same enum.
+///
+/// Each derive macro only ever sees the item it's attached to, never its
+/// sibling derives directly — but the full `#[derive(...)]` attribute list
+/// is part of that item's attributes, so it's visible here.
+fn is_designated_variants_source(attrs: &[Attribute], own_name: &str) -> bool {
+    let first = attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("derive"))
+        .filter_map(|attr| {
+            attr.parse_args_with(
+                syn::punctuated::Punctuated::<syn::Path, Token![,]>::parse_terminated,
+            )
+            .ok()
+        })
+        .flatten()
+        .filter_map(|path| path.get_ident().map(|ident| ident.to_string()))
+        .find(|name| VARIANT_PREDICATE_DERIVES.contains(&name.as_str()));
+
+    first.as_deref() == Some(own_name)
+}
+
+fn parse_argtype(attrs: &[Attribute], ident: &Ident) -> syn::Type {
+    let arg_type_attrs: Vec<&Attribute> = attrs
+        .iter()
+        .filter(|attr| matches!(attr.path.get_ident(), Some(ident) if ident == ARG_ATTR_NAME))
+        .collect();
+
+    if arg_type_attrs.len() != 1 {
+        panic!(
+            "Enum `{}` is missing attribute #[execute_with(your_arg_type)]",
+            ident
+        );
+    }
+
+    let arg_type_attr = &arg_type_attrs[0];
+
+    arg_type_attr.parse_args::<syn::Type>().unwrap_or_else(|e| {
+        panic!(
+            "Failed to parse argtype attribute on Enum `{}`: {}",
+            ident, e
+        )
+    })
+}
+
+pub fn execute_derive_macro(input: DeriveInput) -> TokenStream {
+    let enum_ident = input.ident.clone();
+    let predicates = is_designated_variants_source(&input.attrs, "Execute")
+        .then(|| generate_variant_predicates(&enum_ident, &input.data))
+        .unwrap_or_default();
+    let matcher = base_derive_macro(input, Opts::None, false);
+
+    quote! {
+        #predicates
+
+        #[automatically_derived]
+        impl Execute for #enum_ident {
+            fn execute(self) {
+                #matcher
+            }
+        }
+    }
+}
+
+pub fn execute_with_derive_macro(input: DeriveInput) -> TokenStream {
+    let enum_ident = input.ident.clone();
+    let arg_type = parse_argtype(&input.attrs, &input.ident);
+    let predicates = is_designated_variants_source(&input.attrs, "ExecuteWith")
+        .then(|| generate_variant_predicates(&enum_ident, &input.data))
+        .unwrap_or_default();
+    let matcher = base_derive_macro(input, Opts::RefParam("args"), false);
+
+    quote! {
+        #predicates
+
+        #[automatically_derived]
+        impl ExecuteWith<#arg_type> for #enum_ident {
+            fn execute_with(self, args: &#arg_type) {
+                #matcher
+            }
+        }
+    }
+}
+
+pub fn execute_with_mut_derive_macro(input: DeriveInput) -> TokenStream {
+    let enum_ident = input.ident.clone();
+    let arg_type = parse_argtype(&input.attrs, &input.ident);
+    let predicates = is_designated_variants_source(&input.attrs, "ExecuteWithMut")
+        .then(|| generate_variant_predicates(&enum_ident, &input.data))
+        .unwrap_or_default();
+    let matcher = base_derive_macro(input, Opts::RefMutParam("args"), false);
+
+    quote! {
+        #predicates
+
+        #[automatically_derived]
+        impl ExecuteWithMut<#arg_type> for #enum_ident {
+            fn execute_with_mut(self, args: &mut #arg_type) {
+                #matcher
+            }
+        }
+    }
+}
+
+const TRY_EXECUTE_ATTR_NAME: &str = "try_execute";
+
+/// The parsed contents of a `#[try_execute(...)]` attribute: an optional
+/// leading context argument type (for `TryExecuteWith`/`TryExecuteWithMut`),
+/// followed by an optional `error = Type` override, e.g. the `Arg` and
+/// `MyError` in `#[try_execute(Arg, error = MyError)]`.
+struct TryExecuteSpec {
+    arg_type: Option<syn::Type>,
+    error_type: Option<syn::Type>,
+}
+
+impl Parse for TryExecuteSpec {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut arg_type = None;
+        let mut error_type = None;
+
+        if !input.is_empty() {
+            // `ident =` is an `error = ...` binding; a bare `ident` not
+            // followed by `=` is the leading context argument type.
+            let is_error_binding = {
+                let fork = input.fork();
+                fork.parse::<Ident>().is_ok() && fork.peek(Token![=])
+            };
+
+            if !is_error_binding {
+                arg_type = Some(input.parse::<syn::Type>()?);
+
+                if input.peek(Token![,]) {
+                    input.parse::<Token![,]>()?;
+                }
+            }
+        }
+
+        while !input.is_empty() {
+            let name: Ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+
+            if name == "error" {
+                error_type = Some(input.parse::<syn::Type>()?);
+            } else {
+                return Err(syn::Error::new_spanned(
+                    &name,
+                    format!("unknown `try_execute` option `{}`", name),
+                ));
+            }
+
+            if input.peek(Token![,]) {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(TryExecuteSpec {
+            arg_type,
+            error_type,
+        })
+    }
+}
+
+fn parse_try_execute_spec(attrs: &[Attribute], ident: &Ident) -> TryExecuteSpec {
+    let try_execute_attrs: Vec<&Attribute> = attrs
+        .iter()
+        .filter(|attr| matches!(attr.path.get_ident(), Some(a) if a == TRY_EXECUTE_ATTR_NAME))
+        .collect();
+
+    if try_execute_attrs.is_empty() {
+        return TryExecuteSpec {
+            arg_type: None,
+            error_type: None,
+        };
+    }
+
+    if try_execute_attrs.len() != 1 {
+        panic!(
+            "Enum `{}` has more than one #[try_execute(...)] attribute",
+            ident
+        );
+    }
+
+    try_execute_attrs[0]
+        .parse_args::<TryExecuteSpec>()
+        .unwrap_or_else(|e| {
+            panic!(
+                "Failed to parse #[try_execute(...)] on Enum `{}`: {}",
+                ident, e
+            )
+        })
+}
+
+/// The error type used by `TryExecute`/`TryExecuteWith`/`TryExecuteWithMut`
+/// derives when no `error = ...` override is given in `#[try_execute(...)]`.
+fn default_error_type() -> syn::Type {
+    syn::parse_str("Box<dyn std::error::Error>").expect("default error type is valid syntax")
+}
+
+pub fn try_execute_derive_macro(input: DeriveInput) -> TokenStream {
+    let enum_ident = input.ident.clone();
+    let spec = parse_try_execute_spec(&input.attrs, &input.ident);
+
+    if spec.arg_type.is_some() {
+        panic!(
+            "Enum `{}`: `TryExecute` takes no context argument in #[try_execute(...)], only `error = ...`",
+            enum_ident
+        );
+    }
+
+    let error_type = spec.error_type.unwrap_or_else(default_error_type);
+    let matcher = base_derive_macro(input, Opts::None, true);
+
+    quote! {
+        #[automatically_derived]
+        impl TryExecute for #enum_ident {
+            type Error = #error_type;
+
+            fn try_execute(self) -> Result<(), Self::Error> {
+                #matcher
+            }
+        }
+    }
+}
+
+pub fn try_execute_with_derive_macro(input: DeriveInput) -> TokenStream {
+    let enum_ident = input.ident.clone();
+    let spec = parse_try_execute_spec(&input.attrs, &input.ident);
+    let arg_type = spec.arg_type.unwrap_or_else(|| {
+        panic!(
+            "Enum `{}` is missing attribute #[try_execute(your_arg_type)]",
+            enum_ident
+        )
+    });
+    let error_type = spec.error_type.unwrap_or_else(default_error_type);
+    let matcher = base_derive_macro(input, Opts::RefParam("args"), true);
+
+    quote! {
+        #[automatically_derived]
+        impl TryExecuteWith<#arg_type> for #enum_ident {
+            type Error = #error_type;
+
+            fn try_execute_with(self, args: &#arg_type) -> Result<(), Self::Error> {
+                #matcher
+            }
+        }
+    }
+}
+
+pub fn try_execute_with_mut_derive_macro(input: DeriveInput) -> TokenStream {
+    let enum_ident = input.ident.clone();
+    let spec = parse_try_execute_spec(&input.attrs, &input.ident);
+    let arg_type = spec.arg_type.unwrap_or_else(|| {
+        panic!(
+            "Enum `{}` is missing attribute #[try_execute(your_arg_type)]",
+            enum_ident
+        )
+    });
+    let error_type = spec.error_type.unwrap_or_else(default_error_type);
+    let matcher = base_derive_macro(input, Opts::RefMutParam("args"), true);
+
+    quote! {
+        #[automatically_derived]
+        impl TryExecuteWithMut<#arg_type> for #enum_ident {
+            type Error = #error_type;
+
+            fn try_execute_with_mut(self, args: &mut #arg_type) -> Result<(), Self::Error> {
+                #matcher
+            }
+        }
+    }
+}
@@ -5,23 +5,44 @@ use impls::*;
 
 mod impls;
 
-#[proc_macro_derive(Execute, attributes(handler))]
+#[proc_macro_derive(Execute, attributes(handler, delegate))]
 pub fn derive_execute(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
 
     execute_derive_macro(input).into()
 }
 
-#[proc_macro_derive(ExecuteWith, attributes(handler, execute_with))]
+#[proc_macro_derive(ExecuteWith, attributes(handler, execute_with, delegate))]
 pub fn derive_execute_with(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
 
     execute_with_derive_macro(input).into()
 }
 
-#[proc_macro_derive(ExecuteWithMut, attributes(handler, execute_with))]
+#[proc_macro_derive(ExecuteWithMut, attributes(handler, execute_with, delegate))]
 pub fn derive_execute_with_mut(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
 
     execute_with_mut_derive_macro(input).into()
 }
+
+#[proc_macro_derive(TryExecute, attributes(handler, delegate, try_execute))]
+pub fn derive_try_execute(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    try_execute_derive_macro(input).into()
+}
+
+#[proc_macro_derive(TryExecuteWith, attributes(handler, delegate, try_execute))]
+pub fn derive_try_execute_with(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    try_execute_with_derive_macro(input).into()
+}
+
+#[proc_macro_derive(TryExecuteWithMut, attributes(handler, delegate, try_execute))]
+pub fn derive_try_execute_with_mut(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    try_execute_with_mut_derive_macro(input).into()
+}
@@ -170,10 +170,259 @@ where
     }
 }
 
+/// Extension trait for running only the elements of a pipeline collection
+/// that satisfy a predicate, without rebuilding the collection itself.
+///
+/// Pairs well with the `is_<variant>()` predicate methods generated by the
+/// `Execute`/`ExecuteWith`/`ExecuteWithMut` derives of `enum_pipeline_derive`
+/// (e.g. skip all
+/// `Allocate` ops with `pipeline.execute_filtered(|op| !op.is_allocate())`).
+pub trait ExecuteFiltered {
+    /// The item type yielded by this collection.
+    type Item;
+
+    /// Execute only the elements of this pipeline for which `pred` returns `true`.
+    fn execute_filtered(self, pred: impl Fn(&Self::Item) -> bool);
+}
+
+/// Blanket implementation of the [`ExecuteFiltered`] trait for any type
+/// that can be converted to an [`Iterator`] over some type that
+/// also implements [`Execute`]
+///
+/// ## Example
+///
+/// ```
+/// use enum_pipeline::{Execute, ExecuteFiltered};
+/// use std::cell::RefCell;
+///
+/// enum Operations<'a> {
+///     Allocate(&'a RefCell<u32>),
+///     Run(&'a RefCell<u32>),
+/// }
+///
+/// impl Operations<'_> {
+///     fn is_allocate(&self) -> bool {
+///         matches!(self, Operations::Allocate(_))
+///     }
+/// }
+///
+/// impl Execute for Operations<'_> {
+///     fn execute(self) {
+///         match self {
+///             Operations::Allocate(cell) => *cell.borrow_mut() += 1,
+///             Operations::Run(cell) => *cell.borrow_mut() += 10,
+///         }
+///     }
+/// }
+///
+/// let acc = RefCell::new(0u32);
+/// let my_op_pipeline = vec![Operations::Allocate(&acc), Operations::Run(&acc)];
+///
+/// my_op_pipeline.execute_filtered(|op| !op.is_allocate());
+/// assert_eq!(10, *acc.borrow());
+/// ```
+impl<T> ExecuteFiltered for T
+where
+    T: IntoIterator,
+    T::Item: Execute,
+{
+    type Item = T::Item;
+
+    fn execute_filtered(self, pred: impl Fn(&Self::Item) -> bool) {
+        self.into_iter()
+            .filter(|item| pred(item))
+            .for_each(|item| item.execute());
+    }
+}
+
+/// Extension trait for running only the elements of a pipeline collection
+/// that satisfy a predicate, threading a shared argument of type `TArg`
+/// through each execution. See [`ExecuteFiltered`] for the infallible,
+/// argument-less version.
+pub trait ExecuteFilteredWith<TArg: ?Sized> {
+    /// The item type yielded by this collection.
+    type Item;
+
+    /// Execute only the elements of this pipeline for which `pred` returns `true`.
+    fn execute_filtered_with(self, arg: &TArg, pred: impl Fn(&Self::Item) -> bool);
+}
+
+/// Blanket implementation of the [`ExecuteFilteredWith`] trait for any type
+/// that can be converted to an [`Iterator`] over some type that
+/// also implements [`ExecuteWith`]
+impl<T, TArg: ?Sized> ExecuteFilteredWith<TArg> for T
+where
+    T: IntoIterator,
+    T::Item: ExecuteWith<TArg>,
+{
+    type Item = T::Item;
+
+    fn execute_filtered_with(self, arg: &TArg, pred: impl Fn(&Self::Item) -> bool) {
+        self.into_iter()
+            .filter(|item| pred(item))
+            .for_each(move |item| item.execute_with(arg));
+    }
+}
+
+/// Extension trait for running only the elements of a pipeline collection
+/// that satisfy a predicate, threading a shared mutable argument of type
+/// `TArg` through each execution. See [`ExecuteFiltered`] for the
+/// infallible, argument-less version.
+pub trait ExecuteFilteredWithMut<TArg: ?Sized> {
+    /// The item type yielded by this collection.
+    type Item;
+
+    /// Execute only the elements of this pipeline for which `pred` returns `true`.
+    fn execute_filtered_with_mut(self, arg: &mut TArg, pred: impl Fn(&Self::Item) -> bool);
+}
+
+/// Blanket implementation of the [`ExecuteFilteredWithMut`] trait for any type
+/// that can be converted to an [`Iterator`] over some type that
+/// also implements [`ExecuteWithMut`]
+impl<T, TArg: ?Sized> ExecuteFilteredWithMut<TArg> for T
+where
+    T: IntoIterator,
+    T::Item: ExecuteWithMut<TArg>,
+{
+    type Item = T::Item;
+
+    fn execute_filtered_with_mut(self, arg: &mut TArg, pred: impl Fn(&Self::Item) -> bool) {
+        self.into_iter()
+            .filter(|item| pred(item))
+            .for_each(move |item| item.execute_with_mut(arg));
+    }
+}
+
+/// Provides a fallible execute handler for pipelines. Unlike [`Execute`],
+/// a handler may fail, aborting the pipeline and surfacing the cause.
+pub trait TryExecute {
+    /// The error a handler may fail with.
+    type Error;
+
+    /// Execute a pipeline call to this instance.
+    /// Responsible for invoking the relevant handler(s).
+    fn try_execute(self) -> Result<(), Self::Error>;
+}
+
+/// Provides a fallible execute handler for pipelines, with an argument of
+/// type `TArg`. Unlike [`ExecuteWith`], a handler may fail, aborting the
+/// pipeline and surfacing the cause.
+pub trait TryExecuteWith<TArg: ?Sized> {
+    /// The error a handler may fail with.
+    type Error;
+
+    /// Execute a pipeline call to this instance with an argument.
+    /// Responsible for invoking the relevant handler(s).
+    fn try_execute_with(self, arg: &TArg) -> Result<(), Self::Error>;
+}
+
+/// Provides a fallible execute handler for pipelines, with a mutable
+/// argument of type `TArg`. Unlike [`ExecuteWithMut`], a handler may fail,
+/// aborting the pipeline and surfacing the cause.
+pub trait TryExecuteWithMut<TArg: ?Sized> {
+    /// The error a handler may fail with.
+    type Error;
+
+    /// Execute a pipeline call to this instance with a mutable argument.
+    /// Responsible for invoking the relevant handler(s).
+    fn try_execute_with_mut(self, arg: &mut TArg) -> Result<(), Self::Error>;
+}
+
+/// Blanket implementation of the [`TryExecute`] trait for any type
+/// that can be converted to an [`Iterator`] over some type that
+/// also implements [`TryExecute`]. Stops and returns the first `Err`
+/// encountered, without running the remaining elements.
+///
+/// ## Example
+///
+/// ```
+/// use enum_pipeline::TryExecute;
+///
+/// enum Operations {
+///     AddOne(i32),
+///     Fail,
+/// }
+///
+/// impl TryExecute for Operations {
+///     type Error = String;
+///
+///     fn try_execute(self) -> Result<(), String> {
+///         match self {
+///             Operations::AddOne(_) => Ok(()),
+///             Operations::Fail => Err(String::from("boom")),
+///         }
+///     }
+/// }
+///
+/// let my_op_pipeline = vec![Operations::AddOne(1), Operations::Fail, Operations::AddOne(1)];
+///
+/// assert_eq!(Err(String::from("boom")), my_op_pipeline.try_execute());
+/// ```
+impl<T> TryExecute for T
+where
+    T: IntoIterator,
+    T::Item: TryExecute,
+{
+    type Error = <T::Item as TryExecute>::Error;
+
+    fn try_execute(self) -> Result<(), Self::Error> {
+        for item in self.into_iter() {
+            item.try_execute()?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Blanket implementation of the [`TryExecuteWith`] trait for any type
+/// that can be converted to an [`Iterator`] over some type that
+/// also implements [`TryExecuteWith`]. Stops and returns the first `Err`
+/// encountered, without running the remaining elements.
+impl<T, TArg: ?Sized> TryExecuteWith<TArg> for T
+where
+    T: IntoIterator,
+    T::Item: TryExecuteWith<TArg>,
+{
+    type Error = <T::Item as TryExecuteWith<TArg>>::Error;
+
+    fn try_execute_with(self, arg: &TArg) -> Result<(), Self::Error> {
+        for item in self.into_iter() {
+            item.try_execute_with(arg)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Blanket implementation of the [`TryExecuteWithMut`] trait for any type
+/// that can be converted to an [`Iterator`] over some type that
+/// also implements [`TryExecuteWithMut`]. Stops and returns the first
+/// `Err` encountered, without running the remaining elements.
+impl<T, TArg: ?Sized> TryExecuteWithMut<TArg> for T
+where
+    T: IntoIterator,
+    T::Item: TryExecuteWithMut<TArg>,
+{
+    type Error = <T::Item as TryExecuteWithMut<TArg>>::Error;
+
+    fn try_execute_with_mut(self, arg: &mut TArg) -> Result<(), Self::Error> {
+        for item in self.into_iter() {
+            item.try_execute_with_mut(arg)?;
+        }
+
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{Execute, ExecuteWith, ExecuteWithMut};
-    use enum_pipeline_derive::{Execute, ExecuteWith, ExecuteWithMut};
+    use crate::{
+        Execute, ExecuteFiltered, ExecuteWith, ExecuteWithMut, TryExecute, TryExecuteWith,
+        TryExecuteWithMut,
+    };
+    use enum_pipeline_derive::{
+        Execute, ExecuteWith, ExecuteWithMut, TryExecute, TryExecuteWith, TryExecuteWithMut,
+    };
 
     #[derive(Execute)]
     enum VoidDispatchPipeline {
@@ -331,6 +580,35 @@ mod tests {
         }
     }
 
+    #[derive(ExecuteWith)]
+    #[execute_with(std::cell::RefCell<String>)]
+    enum MacroGenericArgPipeline {
+        #[handler(handle_allocate)]
+        Allocate,
+        #[handler(handle_init)]
+        Init,
+    }
+
+    impl MacroGenericArgPipeline {
+        fn handle_allocate(data: &std::cell::RefCell<String>) {
+            data.borrow_mut().push_str("[alloc]");
+        }
+
+        fn handle_init(data: &std::cell::RefCell<String>) {
+            data.borrow_mut().push_str("[init]");
+        }
+    }
+
+    #[test]
+    fn macro_generic_arg_pipeline_works() {
+        let data = std::cell::RefCell::new(String::new());
+
+        vec![MacroGenericArgPipeline::Init, MacroGenericArgPipeline::Allocate]
+            .execute_with(&data);
+
+        assert_eq!("[init][alloc]", *data.borrow());
+    }
+
     #[derive(Default)]
     struct MacroMutRefData {
         a_count: i32,
@@ -393,4 +671,352 @@ mod tests {
         assert_eq!(1, arg.a_count);
         assert_eq!(1, arg.b_count);
     }
+
+    #[derive(Execute)]
+    enum PositionalArgPipeline {
+        #[handler(handle_run, __1, 3)]
+        Run(i32),
+        #[handler(handle_reset, __1)]
+        Reset(i32),
+    }
+
+    static mut POSITIONAL_RUN_CALLS: Vec<(i32, i32)> = Vec::new();
+    static mut POSITIONAL_RESET_VALUE: i32 = 0;
+
+    impl PositionalArgPipeline {
+        fn handle_run(speed: i32, retries: i32) {
+            unsafe {
+                POSITIONAL_RUN_CALLS.push((speed, retries));
+            }
+        }
+
+        fn handle_reset(ctx: i32) {
+            unsafe {
+                POSITIONAL_RESET_VALUE = ctx;
+            }
+        }
+    }
+
+    #[test]
+    fn positional_arg_pipeline_works() {
+        let pipeline = vec![PositionalArgPipeline::Run(7), PositionalArgPipeline::Reset(42)];
+
+        pipeline.execute();
+
+        unsafe {
+            assert_eq!(vec![(7, 3)], POSITIONAL_RUN_CALLS);
+            assert_eq!(42, POSITIONAL_RESET_VALUE);
+        }
+    }
+
+    #[derive(Execute)]
+    enum SubOp {
+        #[handler(handle_add)]
+        Add(i32),
+        #[handler(handle_sub)]
+        Sub(i32),
+    }
+
+    static mut SUB_OP_TOTAL: i32 = 0;
+
+    impl SubOp {
+        fn handle_add(v: i32) {
+            unsafe {
+                SUB_OP_TOTAL += v;
+            }
+        }
+
+        fn handle_sub(v: i32) {
+            unsafe {
+                SUB_OP_TOTAL -= v;
+            }
+        }
+    }
+
+    #[derive(Execute)]
+    enum TopLevelPipeline {
+        #[delegate]
+        Sub(Vec<SubOp>),
+        #[handler(handle_reset)]
+        Reset,
+    }
+
+    static mut TOP_LEVEL_RESET_COUNT: i32 = 0;
+
+    impl TopLevelPipeline {
+        fn handle_reset() {
+            unsafe {
+                TOP_LEVEL_RESET_COUNT += 1;
+            }
+        }
+    }
+
+    #[test]
+    fn delegate_pipeline_works() {
+        let pipeline = vec![
+            TopLevelPipeline::Sub(vec![SubOp::Add(5), SubOp::Sub(2)]),
+            TopLevelPipeline::Reset,
+        ];
+
+        pipeline.execute();
+
+        unsafe {
+            assert_eq!(3, SUB_OP_TOTAL);
+            assert_eq!(1, TOP_LEVEL_RESET_COUNT);
+        }
+    }
+
+    #[derive(Execute)]
+    enum PredicatePipeline {
+        #[handler(handle_allocate)]
+        Allocate(f32, f32),
+        #[handler(handle_run)]
+        Run(f32),
+    }
+
+    impl PredicatePipeline {
+        fn handle_allocate(_w: f32, _h: f32) {}
+
+        fn handle_run(_speed: f32) {}
+    }
+
+    #[test]
+    fn is_variant_predicates_work() {
+        let allocate = PredicatePipeline::Allocate(1.0, 2.0);
+        let run = PredicatePipeline::Run(1.0);
+
+        assert!(allocate.is_allocate());
+        assert!(!allocate.is_run());
+        assert!(run.is_run());
+        assert!(!run.is_allocate());
+
+        assert_eq!("Allocate", allocate.variant_name());
+        assert_eq!("Run", run.variant_name());
+    }
+
+    struct PredicateRefData {
+        total: i32,
+    }
+
+    #[derive(Execute, ExecuteWith)]
+    #[execute_with(PredicateRefData)]
+    enum MultiPredicateSourcePipeline {
+        // explicit argument lists, so both derives emit the same call
+        #[handler(handle_a, 0)]
+        A,
+        #[handler(handle_b, 0)]
+        B,
+    }
+
+    impl MultiPredicateSourcePipeline {
+        fn handle_a(_n: i32) {}
+
+        fn handle_b(n: i32) {
+            assert!(n >= 0);
+        }
+    }
+
+    #[test]
+    fn is_variant_predicates_survive_multiple_predicate_deriving_derives() {
+        // `Execute` and `ExecuteWith` both generate `is_<variant>()`/
+        // `variant_name()`; deriving both on one enum must not emit the
+        // inherent `impl` block twice.
+        let a = MultiPredicateSourcePipeline::A;
+
+        assert!(a.is_a());
+        assert!(!a.is_b());
+        assert_eq!("A", a.variant_name());
+    }
+
+    #[test]
+    fn execute_filtered_skips_non_matching_variants() {
+        static mut RUN_COUNT: i32 = 0;
+
+        enum FilterPipeline {
+            Allocate,
+            Run,
+        }
+
+        impl FilterPipeline {
+            fn is_allocate(&self) -> bool {
+                matches!(self, FilterPipeline::Allocate)
+            }
+        }
+
+        impl Execute for FilterPipeline {
+            fn execute(self) {
+                if let FilterPipeline::Run = self {
+                    unsafe {
+                        RUN_COUNT += 1;
+                    }
+                }
+            }
+        }
+
+        let pipeline = vec![
+            FilterPipeline::Allocate,
+            FilterPipeline::Run,
+            FilterPipeline::Allocate,
+        ];
+
+        pipeline.execute_filtered(|op| !op.is_allocate());
+
+        unsafe {
+            assert_eq!(1, RUN_COUNT);
+        }
+    }
+
+    #[derive(TryExecute)]
+    #[try_execute(error = String)]
+    enum TryVoidPipeline {
+        #[handler(handle_one)]
+        One,
+        #[handler(handle_fail)]
+        Fail,
+    }
+
+    static mut TRY_VOID_ONE_COUNT: i32 = 0;
+
+    impl TryVoidPipeline {
+        fn handle_one() -> Result<(), String> {
+            unsafe {
+                TRY_VOID_ONE_COUNT += 1;
+            }
+            Ok(())
+        }
+
+        fn handle_fail() -> Result<(), String> {
+            Err(String::from("failed"))
+        }
+    }
+
+    #[test]
+    fn try_execute_short_circuits_on_first_error() {
+        let pipeline = vec![
+            TryVoidPipeline::One,
+            TryVoidPipeline::Fail,
+            TryVoidPipeline::One,
+        ];
+
+        let result = pipeline.try_execute();
+
+        assert_eq!(Err(String::from("failed")), result);
+        unsafe {
+            assert_eq!(1, TRY_VOID_ONE_COUNT);
+        }
+    }
+
+    struct TryRefData {
+        mult: i32,
+    }
+
+    #[derive(TryExecuteWith)]
+    #[try_execute(TryRefData, error = String)]
+    enum TryRefPipeline {
+        #[handler(handle_run, __1, args.mult)]
+        Run(i32),
+    }
+
+    static mut TRY_REF_RUN_TOTAL: i32 = 0;
+
+    impl TryRefPipeline {
+        fn handle_run(speed: i32, mult: i32) -> Result<(), String> {
+            if speed < 0 {
+                return Err(String::from("negative speed"));
+            }
+
+            unsafe {
+                TRY_REF_RUN_TOTAL += speed * mult;
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn try_execute_with_works() {
+        let pipeline = vec![TryRefPipeline::Run(3), TryRefPipeline::Run(4)];
+        let data = TryRefData { mult: 2 };
+
+        let result = pipeline.try_execute_with(&data);
+
+        assert_eq!(Ok(()), result);
+        unsafe {
+            assert_eq!(14, TRY_REF_RUN_TOTAL);
+        }
+    }
+
+    #[derive(Default)]
+    struct TryMutData {
+        total: i32,
+    }
+
+    #[derive(TryExecuteWithMut)]
+    #[try_execute(TryMutData)]
+    enum TryMutSubOp {
+        #[handler(handle_add)]
+        Add(i32),
+    }
+
+    impl TryMutSubOp {
+        fn handle_add(v: i32, data: &mut TryMutData) -> Result<(), Box<dyn std::error::Error>> {
+            data.total += v;
+            Ok(())
+        }
+    }
+
+    #[derive(TryExecuteWithMut)]
+    #[try_execute(TryMutData)]
+    enum TryMutPipeline {
+        #[delegate]
+        Sub(Vec<TryMutSubOp>),
+    }
+
+    #[test]
+    fn try_execute_with_mut_delegate_uses_default_error_type() {
+        let pipeline = vec![TryMutPipeline::Sub(vec![
+            TryMutSubOp::Add(5),
+            TryMutSubOp::Add(3),
+        ])];
+        let mut data = TryMutData::default();
+
+        let result = pipeline.try_execute_with_mut(&mut data);
+
+        assert!(result.is_ok());
+        assert_eq!(8, data.total);
+    }
+
+    #[derive(TryExecuteWith)]
+    #[try_execute(std::cell::RefCell<String>, error = String)]
+    enum TryMacroGenericArgPipeline {
+        #[handler(handle_allocate)]
+        Allocate,
+        #[handler(handle_init)]
+        Init,
+    }
+
+    impl TryMacroGenericArgPipeline {
+        fn handle_allocate(data: &std::cell::RefCell<String>) -> Result<(), String> {
+            data.borrow_mut().push_str("[alloc]");
+            Ok(())
+        }
+
+        fn handle_init(data: &std::cell::RefCell<String>) -> Result<(), String> {
+            data.borrow_mut().push_str("[init]");
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn try_macro_generic_arg_pipeline_works() {
+        let data = std::cell::RefCell::new(String::new());
+
+        let result = vec![
+            TryMacroGenericArgPipeline::Init,
+            TryMacroGenericArgPipeline::Allocate,
+        ]
+        .try_execute_with(&data);
+
+        assert_eq!(Ok(()), result);
+        assert_eq!("[init][alloc]", data.borrow().as_str());
+    }
 }